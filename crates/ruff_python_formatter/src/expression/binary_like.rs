@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::num::NonZeroUsize;
 use std::ops::{Deref, Index};
 
@@ -5,7 +6,8 @@ use smallvec::SmallVec;
 
 use ruff_formatter::write;
 use ruff_python_ast::{
-    Constant, Expr, ExprAttribute, ExprBinOp, ExprCompare, ExprConstant, ExprUnaryOp, UnaryOp,
+    CmpOp, Constant, Expr, ExprAttribute, ExprBinOp, ExprBoolOp, ExprCompare, ExprConstant,
+    ExprUnaryOp, UnaryOp,
 };
 
 use crate::comments::{leading_comments, trailing_comments, Comments, SourceComment};
@@ -22,28 +24,85 @@ use crate::prelude::*;
 pub(super) enum BinaryLike<'a> {
     BinaryExpression(&'a ExprBinOp),
     CompareExpression(&'a ExprCompare),
+    BoolOpExpression(&'a ExprBoolOp),
 }
 
 impl<'a> BinaryLike<'a> {
     /// Flattens the hierarchical binary expression into a flat operand, operator, operand... sequence.
     ///
     /// See [`FlatBinaryExpressionSlice`] for an in depth explanation.
-    fn flatten(self, comments: &'a Comments<'a>, source: &str) -> FlatBinaryExpression<'a> {
+    fn flatten(
+        self,
+        comments: &'a Comments<'a>,
+        source: &str,
+        preview: bool,
+    ) -> FlatBinaryExpression<'a> {
         fn recurse_compare<'a>(
             compare: &'a ExprCompare,
             leading_comments: &'a [SourceComment],
             trailing_comments: &'a [SourceComment],
+            preview: bool,
             comments: &'a Comments,
             source: &str,
             parts: &mut SmallVec<[OperandOrOperator<'a>; 8]>,
         ) {
             parts.reserve(compare.comparators.len() * 2 + 1);
 
+            // Under the preview style, normalize a two-operand "Yoda" comparison that writes a
+            // literal constant on the left (e.g. `42 < x`) into the conventional
+            // constant-on-the-right form (`x > 42`) by flipping the comparator and swapping the
+            // operands. The outer-chain `leading_comments`/`trailing_comments` stay with the left
+            // and right boundary of the chain, and the operands travel through `rec` exactly as
+            // the unflipped path would so their own comments are re-homed the same way.
+            if preview {
+                if let Some((left, operator, right)) = yoda_flip(compare, comments) {
+                    let parent = Some(OperatorSymbol::Comparator(operator));
+
+                    rec(
+                        Operand::Left {
+                            expression: left,
+                            leading_comments,
+                        },
+                        parent,
+                        preview,
+                        comments,
+                        source,
+                        parts,
+                    );
+
+                    parts.push(OperandOrOperator::Operator(Operator {
+                        symbol: OperatorSymbol::Comparator(operator),
+                        trailing_comments: &[],
+                    }));
+
+                    rec(
+                        Operand::Right {
+                            expression: right,
+                            trailing_comments,
+                        },
+                        parent,
+                        preview,
+                        comments,
+                        source,
+                        parts,
+                    );
+
+                    return;
+                }
+            }
+
+            let parent = compare
+                .ops
+                .first()
+                .map(|op| OperatorSymbol::Comparator(*op));
+
             rec(
                 Operand::Left {
                     expression: &compare.left,
                     leading_comments,
                 },
+                parent,
+                preview,
                 comments,
                 source,
                 parts,
@@ -64,7 +123,14 @@ impl<'a> BinaryLike<'a> {
                         trailing_comments: &[],
                     }));
 
-                    rec(Operand::Middle { expression }, comments, source, parts);
+                    rec(
+                        Operand::Middle { expression },
+                        parent,
+                        preview,
+                        comments,
+                        source,
+                        parts,
+                    );
                 }
 
                 parts.push(OperandOrOperator::Operator(Operator {
@@ -77,6 +143,8 @@ impl<'a> BinaryLike<'a> {
                         expression: last_expression,
                         trailing_comments,
                     },
+                    parent,
+                    preview,
                     comments,
                     source,
                     parts,
@@ -88,15 +156,20 @@ impl<'a> BinaryLike<'a> {
             binary: &'a ExprBinOp,
             leading_comments: &'a [SourceComment],
             trailing_comments: &'a [SourceComment],
+            preview: bool,
             comments: &'a Comments,
             source: &str,
             parts: &mut SmallVec<[OperandOrOperator<'a>; 8]>,
         ) {
+            let parent = Some(OperatorSymbol::Binary(binary.op));
+
             rec(
                 Operand::Left {
                     leading_comments,
                     expression: &binary.left,
                 },
+                parent,
+                preview,
                 comments,
                 source,
                 parts,
@@ -112,21 +185,98 @@ impl<'a> BinaryLike<'a> {
                     expression: binary.right.as_ref(),
                     trailing_comments,
                 },
+                parent,
+                preview,
+                comments,
+                source,
+                parts,
+            );
+        }
+
+        fn recurse_boolop<'a>(
+            bool_op: &'a ExprBoolOp,
+            leading_comments: &'a [SourceComment],
+            trailing_comments: &'a [SourceComment],
+            preview: bool,
+            comments: &'a Comments,
+            source: &str,
+            parts: &mut SmallVec<[OperandOrOperator<'a>; 8]>,
+        ) {
+            parts.reserve(bool_op.values.len() * 2);
+
+            let parent = Some(OperatorSymbol::Bool(bool_op.op));
+
+            let Some((first_expression, rest)) = bool_op.values.split_first() else {
+                return;
+            };
+
+            rec(
+                Operand::Left {
+                    expression: first_expression,
+                    leading_comments,
+                },
+                parent,
+                preview,
                 comments,
                 source,
                 parts,
             );
+
+            if let Some((last_expression, middle_expressions)) = rest.split_last() {
+                for expression in middle_expressions {
+                    parts.push(OperandOrOperator::Operator(Operator {
+                        symbol: OperatorSymbol::Bool(bool_op.op),
+                        trailing_comments: &[],
+                    }));
+
+                    rec(
+                        Operand::Middle { expression },
+                        parent,
+                        preview,
+                        comments,
+                        source,
+                        parts,
+                    );
+                }
+
+                parts.push(OperandOrOperator::Operator(Operator {
+                    symbol: OperatorSymbol::Bool(bool_op.op),
+                    trailing_comments: &[],
+                }));
+
+                rec(
+                    Operand::Right {
+                        expression: last_expression,
+                        trailing_comments,
+                    },
+                    parent,
+                    preview,
+                    comments,
+                    source,
+                    parts,
+                );
+            }
         }
 
         fn rec<'a>(
             operand: Operand<'a>,
+            parent: Option<OperatorSymbol>,
+            preview: bool,
             comments: &'a Comments,
             source: &str,
             parts: &mut SmallVec<[OperandOrOperator<'a>; 8]>,
         ) {
             let expression = operand.expression();
+
+            // Only flatten a parenthesized operand when the preview style is active and the
+            // parentheses are provably redundant for the operator binding this operand.
+            let parenthesized = is_expression_parenthesized(expression.into(), source);
+            let flatten = !parenthesized
+                || (preview
+                    && parentheses_are_redundant(expression, &operand, parent, comments));
+
             match expression {
-                Expr::BinOp(binary) if !is_expression_parenthesized(expression.into(), source) => {
+                Expr::BinOp(binary) if flatten => {
                     let leading_comments = operand
                         .leading_binary_comments()
                         .unwrap_or_else(|| comments.leading(binary));
@@ -139,14 +289,13 @@ impl<'a> BinaryLike<'a> {
                         binary,
                         leading_comments,
                         trailing_comments,
+                        preview,
                         comments,
                         source,
                         parts,
                     );
                 }
-                Expr::Compare(compare)
-                    if !is_expression_parenthesized(expression.into(), source) =>
-                {
+                Expr::Compare(compare) if flatten => {
                     let leading_comments = operand
                         .leading_binary_comments()
                         .unwrap_or_else(|| comments.leading(compare));
@@ -159,6 +308,26 @@ impl<'a> BinaryLike<'a> {
                         compare,
                         leading_comments,
                         trailing_comments,
+                        preview,
+                        comments,
+                        source,
+                        parts,
+                    );
+                }
+                Expr::BoolOp(bool_op) if flatten => {
+                    let leading_comments = operand
+                        .leading_binary_comments()
+                        .unwrap_or_else(|| comments.leading(bool_op));
+
+                    let trailing_comments = operand
+                        .trailing_binary_comments()
+                        .unwrap_or_else(|| comments.trailing(bool_op));
+
+                    recurse_boolop(
+                        bool_op,
+                        leading_comments,
+                        trailing_comments,
+                        preview,
                         comments,
                         source,
                         parts,
@@ -174,11 +343,15 @@ impl<'a> BinaryLike<'a> {
         match self {
             BinaryLike::BinaryExpression(binary) => {
                 // Leading and trailing comments are handled by the binary's ``FormatNodeRule` implementation.
-                recurse_binary(binary, &[], &[], comments, source, &mut parts);
+                recurse_binary(binary, &[], &[], preview, comments, source, &mut parts);
             }
             BinaryLike::CompareExpression(compare) => {
                 // Leading and trailing comments are handled by the compare's ``FormatNodeRule` implementation.
-                recurse_compare(compare, &[], &[], comments, source, &mut parts);
+                recurse_compare(compare, &[], &[], preview, comments, source, &mut parts);
+            }
+            BinaryLike::BoolOpExpression(bool_op) => {
+                // Leading and trailing comments are handled by the boolean operation's `FormatNodeRule` implementation.
+                recurse_boolop(bool_op, &[], &[], preview, comments, source, &mut parts);
             }
         }
 
@@ -189,7 +362,8 @@ impl<'a> BinaryLike<'a> {
 impl Format<PyFormatContext<'_>> for BinaryLike<'_> {
     fn fmt(&self, f: &mut Formatter<PyFormatContext<'_>>) -> FormatResult<()> {
         let comments = f.context().comments().clone();
-        let flat_binary = self.flatten(&comments, f.context().source());
+        let preview = f.options().preview().is_enabled();
+        let flat_binary = self.flatten(&comments, f.context().source(), preview);
 
         let source = f.context().source();
         let mut string_operands = flat_binary
@@ -367,6 +541,10 @@ const fn is_simple_power_expression(left: &Expr, right: &Expr) -> bool {
 
 /// Return `true` if an [`Expr`] adheres to [Black's definition](https://black.readthedocs.io/en/stable/the_black_code_style/current_style.html#line-breaks-binary-operators)
 /// of a non-complex expression, in the context of a power operation.
+///
+/// This governs hugging of the `**` operator (spacing), which is distinct from whether an
+/// operand needs parentheses; for the latter see [`needs_parentheses_in`], which handles the
+/// `-a ** b` hazard this function only approximates.
 const fn is_simple_power_operand(expr: &Expr) -> bool {
     match expr {
         Expr::UnaryOp(ExprUnaryOp {
@@ -383,6 +561,230 @@ const fn is_simple_power_operand(expr: &Expr) -> bool {
     }
 }
 
+/// If `compare` is a reversible two-operand "Yoda" comparison with a literal constant on the
+/// left, returns `(new_left, flipped_operator, new_right)` — the operands swapped and the
+/// comparator flipped — so the comparison formats with the constant on the right (`42 < x`
+/// becomes `x > 42`).
+///
+/// Returns `None` for genuine comparison chains such as `0 <= i < n`, for the non-reversible
+/// identity/membership operators, and when the left operand is not a literal constant (or the
+/// right operand is also one, in which case flipping gains nothing). It also bails when the
+/// operand moving to the left would need enclosing parentheses there (a lambda, walrus, `yield`,
+/// …), since dropping them changes the parse, and when either operand carries its own comments,
+/// which the swap would otherwise re-home.
+fn yoda_flip<'a>(
+    compare: &'a ExprCompare,
+    comments: &Comments,
+) -> Option<(&'a Expr, CmpOp, &'a Expr)> {
+    // Only two-operand comparisons; real chains are left untouched.
+    let [op] = compare.ops.as_slice() else {
+        return None;
+    };
+    let [right] = compare.comparators.as_slice() else {
+        return None;
+    };
+    let left = compare.left.as_ref();
+
+    if !is_literal_constant(left) || is_literal_constant(right) {
+        return None;
+    }
+
+    let flipped = reverse_comparator(*op)?;
+
+    // A bare tuple or generator reads as part of the surrounding expression once unparenthesized
+    // (`(a, b) > 1` must not become `a, b > 1`); leave these in the Yoda form. Other hazards that
+    // depend on enclosing parentheses are covered by the `needs_parentheses_in` check below.
+    if matches!(right, Expr::Tuple(_) | Expr::GeneratorExp(_)) {
+        return None;
+    }
+
+    // The current right-hand side becomes the new (unparenthesized) left operand; keep the Yoda
+    // form when it is an expression whose meaning depends on enclosing parentheses.
+    if needs_parentheses_in(
+        right,
+        OperatorSymbol::Comparator(flipped),
+        OperandPosition::Left,
+    ) == Parenthesize::Mandatory
+    {
+        return None;
+    }
+
+    // Keep the original form when either operand carries its own comments; swapping would move
+    // them to the other side of the comparison.
+    for operand in [left, right] {
+        if comments.has_leading(operand)
+            || !comments.trailing(operand).is_empty()
+            || !comments.dangling(operand).is_empty()
+        {
+            return None;
+        }
+    }
+
+    Some((right, flipped, left))
+}
+
+/// Reverses a comparison operator so that swapping its operands preserves meaning: `<`↔`>`,
+/// `<=`↔`>=`, and `==`/`!=` map to themselves. The identity and membership tests
+/// (`is`/`is not`/`in`/`not in`) are not reversible and return `None`.
+fn reverse_comparator(op: CmpOp) -> Option<CmpOp> {
+    Some(match op {
+        CmpOp::Lt => CmpOp::Gt,
+        CmpOp::LtE => CmpOp::GtE,
+        CmpOp::Gt => CmpOp::Lt,
+        CmpOp::GtE => CmpOp::LtE,
+        CmpOp::Eq => CmpOp::Eq,
+        CmpOp::NotEq => CmpOp::NotEq,
+        CmpOp::Is | CmpOp::IsNot | CmpOp::In | CmpOp::NotIn => return None,
+    })
+}
+
+/// Returns `true` if `expr` is a literal constant: a number, a string or bytes literal,
+/// `True`/`False`/`None`, or an ellipsis.
+const fn is_literal_constant(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Constant(ExprConstant {
+            value: Constant::Int(_)
+                | Constant::Float(_)
+                | Constant::Complex { .. }
+                | Constant::Str(_)
+                | Constant::Bytes(_)
+                | Constant::Bool(_)
+                | Constant::None
+                | Constant::Ellipsis,
+            ..
+        })
+    )
+}
+
+/// Which operand of a parent operator a child expression occupies.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum OperandPosition {
+    Left,
+    Right,
+}
+
+/// Whether a child expression's parentheses may be dropped when it is placed as a given operand
+/// of a parent operator.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Parenthesize {
+    /// Parentheses must be kept: dropping them changes the meaning (for example `(-a) ** b`),
+    /// produces invalid syntax, or reads ambiguously.
+    Mandatory,
+    /// Parentheses are redundant and may be removed.
+    Redundant,
+}
+
+/// Classifies whether a `child` expression needs parentheses when placed at `position` of the
+/// `parent` operator, keyed on [`OperatorPrecedence`], associativity, and the Python-specific
+/// hazards that silently change meaning when parentheses are dropped.
+///
+/// The formatter strips a parenthesis pair only when the result is [`Parenthesize::Redundant`];
+/// the Yoda-comparison flip likewise refuses to move an operand that is [`Parenthesize::Mandatory`]
+/// to the unparenthesized side.
+fn needs_parentheses_in(
+    child: &Expr,
+    parent: OperatorSymbol,
+    position: OperandPosition,
+) -> Parenthesize {
+    // A unary operand to the left of `**` binds more loosely than the power operator:
+    // `-a ** b` parses as `-(a ** b)`, so dropping the parentheses of `(-a) ** b` changes its
+    // meaning. `await` binds more tightly than `**` (`await a ** b` is `(await a) ** b`), but its
+    // parentheses are kept as well so the boundary stays explicit.
+    if parent.is_pow()
+        && position == OperandPosition::Left
+        && matches!(child, Expr::UnaryOp(_) | Expr::Await(_))
+    {
+        return Parenthesize::Mandatory;
+    }
+
+    // Operands whose meaning depends on the enclosing parentheses must always be wrapped.
+    if matches!(
+        child,
+        Expr::Named(_)
+            | Expr::Yield(_)
+            | Expr::YieldFrom(_)
+            | Expr::Lambda(_)
+            | Expr::Starred(_)
+            | Expr::IfExp(_)
+    ) {
+        return Parenthesize::Mandatory;
+    }
+
+    // Splicing a comparison into either side of another comparison would extend the chain
+    // (e.g. `(a == b) == c` is not `a == b == c`).
+    if matches!(parent, OperatorSymbol::Comparator(_)) && matches!(child, Expr::Compare(_)) {
+        return Parenthesize::Mandatory;
+    }
+
+    let child_precedence = match child {
+        Expr::BinOp(binary) => OperatorSymbol::Binary(binary.op).precedence(),
+        Expr::Compare(_) => OperatorPrecedence::Comparator,
+        Expr::BoolOp(bool_op) => OperatorSymbol::Bool(bool_op.op).precedence(),
+        // Leaves (names, calls, literals, attribute accesses, …) never need parentheses.
+        _ => return Parenthesize::Redundant,
+    };
+
+    match child_precedence.cmp(&parent.precedence()) {
+        // The child binds tighter than the enclosing operator.
+        Ordering::Less => Parenthesize::Redundant,
+        // The child binds more loosely; parentheses are required.
+        Ordering::Greater => Parenthesize::Mandatory,
+        // Same precedence: only the associativity-favored side can drop its parentheses.
+        Ordering::Equal => {
+            let favored = match parent.fixity() {
+                Fixity::Left => OperandPosition::Left,
+                Fixity::Right => OperandPosition::Right,
+                // Non-associative operators re-associate nothing; either side keeps its parens.
+                Fixity::None => return Parenthesize::Mandatory,
+            };
+
+            if position == favored {
+                Parenthesize::Redundant
+            } else {
+                Parenthesize::Mandatory
+            }
+        }
+    }
+}
+
+/// Returns `true` if the author-written parentheses around the binary-like `expression`
+/// are redundant for the operator (`parent`) that binds it in the enclosing chain, and can
+/// therefore be dropped under the preview style. Delegates the precedence/associativity and
+/// Python-hazard reasoning to [`needs_parentheses_in`].
+///
+/// Parentheses are always kept when the inner expression carries its own comments, since
+/// flattening would re-home them.
+fn parentheses_are_redundant(
+    expression: &Expr,
+    operand: &Operand,
+    parent: Option<OperatorSymbol>,
+    comments: &Comments,
+) -> bool {
+    let Some(parent) = parent else {
+        return false;
+    };
+
+    // Keep parentheses that carry their own comments; flattening would re-home them. Inspect the
+    // parenthesized child's own comments rather than `Operand::has_leading_comments`, whose `Left`
+    // arm only sees the outer chain's comment slice and would miss a comment inside the parentheses.
+    if comments.has_leading(expression)
+        || !comments.trailing(expression).is_empty()
+        || !comments.dangling(expression).is_empty()
+    {
+        return false;
+    }
+
+    let position = match operand {
+        Operand::Left { .. } => OperandPosition::Left,
+        // A compare chain's middle operands are, like right operands, never on the
+        // associativity-favored side.
+        Operand::Middle { .. } | Operand::Right { .. } => OperandPosition::Right,
+    };
+
+    needs_parentheses_in(expression, parent, position) == Parenthesize::Redundant
+}
+
 /// Owned [`FlatBinaryExpressionSlice`]. Read the [`FlatBinaryExpressionSlice`] documentation for more details about the data structure.
 #[derive(Debug)]
 struct FlatBinaryExpression<'a>(SmallVec<[OperandOrOperator<'a>; 8]>);
@@ -552,7 +954,9 @@ impl Format<PyFormatContext<'_>> for FlatBinaryExpressionSlice<'_> {
                 let left = self.between_operators(last_operator, index);
                 let right = self.after_operator(index);
 
-                let is_pow = operator_part.symbol.is_pow()
+                // Right-associative power chains whose operands are both simple break toward the
+                // right operand (no surrounding space); all other chains break toward the left.
+                let break_toward_right = operator_part.symbol.fixity() == Fixity::Right
                     && is_simple_power_expression(
                         left.last_operand().expression(),
                         right.first_operand().expression(),
@@ -568,7 +972,7 @@ impl Format<PyFormatContext<'_>> for FlatBinaryExpressionSlice<'_> {
                     trailing_comments(trailing).fmt(f)?;
                 }
 
-                if is_pow {
+                if break_toward_right {
                     in_parentheses_only_soft_line_break().fmt(f)?;
                 } else {
                     in_parentheses_only_soft_line_break_or_space().fmt(f)?;
@@ -583,7 +987,7 @@ impl Format<PyFormatContext<'_>> for FlatBinaryExpressionSlice<'_> {
                     || operator_part.has_trailing_comments()
                 {
                     hard_line_break().fmt(f)?;
-                } else if !is_pow {
+                } else if !break_toward_right {
                     space().fmt(f)?;
                 }
 
@@ -735,10 +1139,24 @@ impl Format<PyFormatContext<'_>> for Operator<'_> {
     }
 }
 
+/// The associativity of an operator, which—alongside precedence—governs how a chain may be
+/// split and where redundant parentheses may be dropped.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Fixity {
+    /// Left-associative: `a - b - c` groups as `(a - b) - c`.
+    Left,
+    /// Right-associative: `a ** b ** c` groups as `a ** (b ** c)`.
+    Right,
+    /// Non-associative: a genuine chain such as `a < b < c` that must not be re-associated or
+    /// regrouped as if the sub-operations were independent.
+    None,
+}
+
 #[derive(Copy, Clone, Debug)]
 enum OperatorSymbol {
     Binary(ruff_python_ast::Operator),
     Comparator(ruff_python_ast::CmpOp),
+    Bool(ruff_python_ast::BoolOp),
 }
 
 impl OperatorSymbol {
@@ -746,10 +1164,25 @@ impl OperatorSymbol {
         matches!(self, OperatorSymbol::Binary(ruff_python_ast::Operator::Pow))
     }
 
+    /// Returns the operator's [`Fixity`].
+    ///
+    /// Comparisons (and the `in`/`is`/`==` family) are non-associative chains, the power
+    /// operator (`**`) is right-associative, and every other binary or boolean operator is
+    /// left-associative.
+    const fn fixity(self) -> Fixity {
+        match self {
+            OperatorSymbol::Comparator(_) => Fixity::None,
+            OperatorSymbol::Binary(ruff_python_ast::Operator::Pow) => Fixity::Right,
+            OperatorSymbol::Binary(_) | OperatorSymbol::Bool(_) => Fixity::Left,
+        }
+    }
+
     fn precedence(self) -> OperatorPrecedence {
         match self {
             OperatorSymbol::Binary(operator) => OperatorPrecedence::from(operator),
             OperatorSymbol::Comparator(_) => OperatorPrecedence::Comparator,
+            OperatorSymbol::Bool(ruff_python_ast::BoolOp::And) => OperatorPrecedence::And,
+            OperatorSymbol::Bool(ruff_python_ast::BoolOp::Or) => OperatorPrecedence::Or,
         }
     }
 }
@@ -759,6 +1192,7 @@ impl Format<PyFormatContext<'_>> for OperatorSymbol {
         match self {
             OperatorSymbol::Binary(operator) => operator.format().fmt(f),
             OperatorSymbol::Comparator(operator) => operator.format().fmt(f),
+            OperatorSymbol::Bool(operator) => operator.format().fmt(f),
         }
     }
 }
@@ -0,0 +1,25 @@
+use ruff_python_ast::AnyNodeRef;
+use ruff_python_ast::ExprBoolOp;
+
+use crate::expression::binary_like::BinaryLike;
+use crate::expression::parentheses::{NeedsParentheses, OptionalParentheses};
+use crate::prelude::*;
+
+#[derive(Default)]
+pub struct FormatExprBoolOp;
+
+impl FormatNodeRule<ExprBoolOp> for FormatExprBoolOp {
+    fn fmt_fields(&self, item: &ExprBoolOp, f: &mut PyFormatter) -> FormatResult<()> {
+        BinaryLike::BoolOpExpression(item).fmt(f)
+    }
+}
+
+impl NeedsParentheses for ExprBoolOp {
+    fn needs_parentheses(
+        &self,
+        _parent: AnyNodeRef,
+        _context: &PyFormatContext,
+    ) -> OptionalParentheses {
+        OptionalParentheses::Multiline
+    }
+}